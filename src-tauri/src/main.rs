@@ -1,29 +1,221 @@
 // Prevents additional console window on Windows in release mode
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use std::process::{Child, Command};
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Mutex;
-use tauri::State;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager, State};
+
+/// Name of the persisted backend config file inside the app's config dir.
+const CONFIG_FILE_NAME: &str = "backend-config.json";
+
+/// How long to poll the backend's health endpoint before giving up.
+const READY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Delay between health-check attempts.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How many captured stdout/stderr lines we keep around for error reporting.
+const OUTPUT_BUFFER_LINES: usize = 50;
+
+/// Restart attempts are delayed by this schedule (seconds), capped at the last entry.
+const RESTART_BACKOFF_SECS: &[u64] = &[1, 2, 4, 8, 16, 30];
+
+/// How long the backend must stay up before we consider it stable and reset the
+/// restart counter back to zero.
+const STABLE_UPTIME: Duration = Duration::from_secs(60);
+
+/// How often the supervisor checks whether the child process has exited.
+const SUPERVISOR_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long to wait for a graceful shutdown to take effect before escalating
+/// to `kill()`, unless overridden by `BackendConfig::shutdown_grace_secs`.
+const DEFAULT_SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+/// How often we poll `try_wait()` while waiting for a graceful shutdown.
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The running Python backend process together with the port it was told to
+/// listen on, so it can be reached for graceful shutdown.
+struct BackendProcess {
+    child: Child,
+    port: u16,
+}
 
 // State to hold the Python backend process
-struct PythonBackend(Mutex<Option<Child>>);
+struct PythonBackend(Mutex<Option<BackendProcess>>);
 
-/// Start the Python backend server
-#[tauri::command]
-fn start_python_backend(state: State<PythonBackend>) -> Result<String, String> {
-    let mut backend = state.0.lock().unwrap();
+/// Set before the app deliberately stops the backend on window close, so the
+/// supervisor knows the resulting exit isn't a crash to recover from.
+struct ShutdownFlag(AtomicBool);
+
+/// User-configurable backend settings, persisted to `CONFIG_FILE_NAME` in the
+/// app's config directory.
+#[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+struct BackendConfig {
+    /// Explicit interpreter path set via `set_python_interpreter`, tried
+    /// before any auto-detection.
+    python_interpreter: Option<String>,
+    /// Grace period (seconds) to wait for a graceful shutdown before
+    /// escalating to `kill()`. Falls back to `DEFAULT_SHUTDOWN_GRACE`.
+    shutdown_grace_secs: Option<u64>,
+    /// Max consecutive restart attempts before the supervisor gives up and
+    /// emits `backend-failed`. Falls back to `DEFAULT_MAX_RESTART_ATTEMPTS`.
+    max_restart_attempts: Option<u32>,
+}
+
+struct BackendConfigState(Mutex<BackendConfig>);
+
+impl BackendConfig {
+    fn load(app_handle: &AppHandle) -> Self {
+        let path = match config_file_path(app_handle) {
+            Some(path) => path,
+            None => return Self::default(),
+        };
+
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, app_handle: &AppHandle) -> Result<(), String> {
+        let path = config_file_path(app_handle)
+            .ok_or_else(|| "Could not resolve app config directory".to_string())?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Failed to create config dir: {}", e))?;
+        }
+
+        let contents = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize backend config: {}", e))?;
+        fs::write(path, contents).map_err(|e| format!("Failed to write config file: {}", e))
+    }
+}
+
+fn config_file_path(app_handle: &AppHandle) -> Option<PathBuf> {
+    app_handle
+        .path_resolver()
+        .app_config_dir()
+        .map(|dir| dir.join(CONFIG_FILE_NAME))
+}
+
+/// Default for `BackendConfig::max_restart_attempts` when unset.
+const DEFAULT_MAX_RESTART_ATTEMPTS: u32 = 5;
+
+/// Supervisor configuration and restart bookkeeping.
+struct BackendSupervisor(Mutex<SupervisorState>);
+
+struct SupervisorState {
+    max_restart_attempts: u32,
+    restart_attempts: u32,
+    started_at: Instant,
+}
+
+impl SupervisorState {
+    fn new(max_restart_attempts: u32) -> Self {
+        Self {
+            max_restart_attempts,
+            restart_attempts: 0,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+/// A single line of output captured from the Python backend's stdout/stderr,
+/// forwarded to the frontend as a `backend-log` event.
+#[derive(Clone, serde::Serialize)]
+struct LogLine {
+    stream: &'static str,
+    line: String,
+}
+
+/// Emitted when the supervisor observes the backend process has exited.
+#[derive(Clone, serde::Serialize)]
+struct BackendCrashed {
+    code: Option<i32>,
+}
+
+/// Emitted when the backend has exceeded its max restart attempts and the
+/// supervisor is giving up.
+#[derive(Clone, serde::Serialize)]
+struct BackendFailed {
+    attempts: u32,
+}
 
-    // Check if already running
-    if let Some(ref mut child) = *backend {
-        if let Ok(None) = child.try_wait() {
-            return Ok("Python backend is already running".to_string());
+/// Emitted once the backend's health endpoint responds successfully.
+#[derive(Clone, serde::Serialize)]
+struct BackendReady {
+    port: u16,
+}
+
+/// Rolling buffer of the most recent stdout/stderr lines, kept around so
+/// startup failures can be reported with useful context.
+struct BackendOutput(Mutex<VecDeque<String>>);
+
+impl BackendOutput {
+    fn push(&self, line: &str) {
+        let mut buffer = self.0.lock().unwrap();
+        if buffer.len() >= OUTPUT_BUFFER_LINES {
+            buffer.pop_front();
         }
+        buffer.push_back(line.to_string());
     }
 
-    // Get the path to the Python backend
-    // In development: use the python-backend directory
-    // In production: bundle the Python backend with the app
-    let backend_path = if cfg!(debug_assertions) {
+    fn captured(&self) -> String {
+        self.0.lock().unwrap().iter().cloned().collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Spawn threads that read the child's stdout/stderr line-by-line, buffer
+/// them for error reporting, and forward each line to the frontend as a
+/// `backend-log` event.
+fn stream_backend_output(child: &mut Child, app_handle: AppHandle) {
+    if let Some(stdout) = child.stdout.take() {
+        let app_handle = app_handle.clone();
+        std::thread::spawn(move || {
+            let reader = BufReader::new(stdout);
+            for line in reader.lines().flatten() {
+                app_handle.state::<BackendOutput>().push(&line);
+                let _ = app_handle.emit_all(
+                    "backend-log",
+                    LogLine {
+                        stream: "stdout",
+                        line,
+                    },
+                );
+            }
+        });
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        std::thread::spawn(move || {
+            let reader = BufReader::new(stderr);
+            for line in reader.lines().flatten() {
+                app_handle.state::<BackendOutput>().push(&line);
+                let _ = app_handle.emit_all(
+                    "backend-log",
+                    LogLine {
+                        stream: "stderr",
+                        line,
+                    },
+                );
+            }
+        });
+    }
+}
+
+/// Get the path to the Python backend.
+/// In development: use the python-backend directory.
+/// In production: bundle the Python backend with the app.
+fn resolve_backend_path() -> PathBuf {
+    if cfg!(debug_assertions) {
         // Development mode
         std::env::current_dir()
             .unwrap()
@@ -37,41 +229,217 @@ fn start_python_backend(state: State<PythonBackend>) -> Result<String, String> {
             .parent()
             .unwrap()
             .join("python-backend")
-    };
+    }
+}
+
+/// The interpreter executable name inside a venv's bin/Scripts directory.
+#[cfg(target_os = "windows")]
+const VENV_PYTHON: &str = "Scripts/python.exe";
+
+#[cfg(not(target_os = "windows"))]
+const VENV_PYTHON: &str = "bin/python";
+
+/// Resolve which Python interpreter to launch the backend with, trying in
+/// order: the user-configured override, a `.venv`/`venv` next to the
+/// backend, then `python3`/`python`/`py` on PATH. Returns an error naming
+/// every candidate that was tried if none resolve.
+fn resolve_python_interpreter(backend_path: &Path, config: &BackendConfig) -> Result<PathBuf, String> {
+    let mut tried = Vec::new();
+
+    if let Some(override_path) = &config.python_interpreter {
+        tried.push(override_path.clone());
+        let path = PathBuf::from(override_path);
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+
+    for venv_dir in [".venv", "venv"] {
+        let candidate = backend_path.join(venv_dir).join(VENV_PYTHON);
+        tried.push(candidate.display().to_string());
+        if candidate.is_file() {
+            return Ok(candidate);
+        }
+    }
+
+    for name in ["python3", "python", "py"] {
+        tried.push(name.to_string());
+        if let Ok(path) = which::which(name) {
+            return Ok(path);
+        }
+    }
+
+    Err(format!(
+        "Could not find a Python interpreter. Tried: {}",
+        tried.join(", ")
+    ))
+}
+
+/// Bind an ephemeral TCP port and release it immediately so the Python
+/// backend can bind it in turn; both sides agree on the port via the `PORT`
+/// env var.
+fn allocate_ephemeral_port() -> Result<u16, String> {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .map_err(|e| format!("Failed to allocate a port for the backend: {}", e))?;
+    listener
+        .local_addr()
+        .map(|addr| addr.port())
+        .map_err(|e| format!("Failed to read allocated port: {}", e))
+}
 
+/// Spawn the Python backend subprocess and wire up its log streaming. Used
+/// both for the initial start and for supervisor-driven restarts. Returns
+/// the port the backend was told to listen on.
+fn spawn_backend_child(app_handle: AppHandle) -> Result<(Child, u16), String> {
+    let backend_path = resolve_backend_path();
     let app_script = backend_path.join("app.py");
 
-    // Start Python backend as a subprocess
-    #[cfg(target_os = "windows")]
-    let python_cmd = "python";
+    let config = app_handle.state::<BackendConfigState>().0.lock().unwrap().clone();
+    let python_cmd = resolve_python_interpreter(&backend_path, &config)?;
 
-    #[cfg(not(target_os = "windows"))]
-    let python_cmd = "python3";
+    let port = allocate_ephemeral_port()?;
 
-    let child = Command::new(python_cmd)
+    let mut child = Command::new(python_cmd)
         .arg(app_script)
         .current_dir(&backend_path)
+        .env("PORT", port.to_string())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .spawn()
         .map_err(|e| format!("Failed to start Python backend: {}", e))?;
 
-    *backend = Some(child);
+    stream_backend_output(&mut child, app_handle);
+
+    Ok((child, port))
+}
+
+/// Poll the backend's `/health` endpoint until it responds successfully or
+/// `READY_TIMEOUT` elapses, returning the captured output on failure.
+fn wait_for_backend_ready(app_handle: &AppHandle, port: u16) -> Result<(), String> {
+    let url = format!("http://127.0.0.1:{}/health", port);
+    let deadline = Instant::now() + READY_TIMEOUT;
+
+    loop {
+        let healthy = ureq::get(&url)
+            .timeout(Duration::from_secs(1))
+            .call()
+            .map(|response| response.status() < 300)
+            .unwrap_or(false);
+
+        if healthy {
+            return Ok(());
+        }
+
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "Backend did not become ready on {} within {:?}.\n\nCaptured output:\n{}",
+                url,
+                READY_TIMEOUT,
+                app_handle.state::<BackendOutput>().captured()
+            ));
+        }
+
+        std::thread::sleep(READY_POLL_INTERVAL);
+    }
+}
+
+/// Start the Python backend server
+#[tauri::command]
+fn start_python_backend(
+    state: State<PythonBackend>,
+    app_handle: AppHandle,
+) -> Result<String, String> {
+    {
+        let mut backend = state.0.lock().unwrap();
+        // Check if already running
+        if let Some(ref mut process) = *backend {
+            if let Ok(None) = process.child.try_wait() {
+                return Ok("Python backend is already running".to_string());
+            }
+        }
+    }
+
+    let (mut child, port) = spawn_backend_child(app_handle.clone())?;
+
+    if let Err(e) = wait_for_backend_ready(&app_handle, port) {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(e);
+    }
+
+    let _ = app_handle.emit_all("backend-ready", BackendReady { port });
+
+    let mut backend = state.0.lock().unwrap();
+    *backend = Some(BackendProcess { child, port });
 
     Ok("Python backend started successfully".to_string())
 }
 
-/// Stop the Python backend server
+/// Ask the backend to shut itself down: POST to its `/shutdown` endpoint,
+/// falling back to `SIGTERM` on Unix if that request doesn't land.
+fn request_graceful_shutdown(port: u16, pid: u32) {
+    let shutdown_url = format!("http://127.0.0.1:{}/shutdown", port);
+    let posted = ureq::post(&shutdown_url)
+        .timeout(Duration::from_secs(2))
+        .call()
+        .is_ok();
+
+    if !posted {
+        #[cfg(unix)]
+        {
+            use nix::sys::signal::{kill, Signal};
+            use nix::unistd::Pid;
+            let _ = kill(Pid::from_raw(pid as i32), Signal::SIGTERM);
+        }
+        #[cfg(not(unix))]
+        let _ = pid;
+    }
+}
+
+/// Stop the Python backend server, preferring a graceful shutdown over a
+/// hard kill so in-flight work and temp files aren't corrupted.
 #[tauri::command]
-fn stop_python_backend(state: State<PythonBackend>) -> Result<String, String> {
+fn stop_python_backend(
+    state: State<PythonBackend>,
+    config_state: State<BackendConfigState>,
+) -> Result<String, String> {
     let mut backend = state.0.lock().unwrap();
 
-    if let Some(mut child) = backend.take() {
-        child
-            .kill()
-            .map_err(|e| format!("Failed to stop Python backend: {}", e))?;
-        Ok("Python backend stopped".to_string())
-    } else {
-        Ok("Python backend is not running".to_string())
+    let Some(mut process) = backend.take() else {
+        return Ok("Python backend is not running".to_string());
+    };
+
+    request_graceful_shutdown(process.port, process.child.id());
+
+    let grace_period = config_state
+        .0
+        .lock()
+        .unwrap()
+        .shutdown_grace_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_SHUTDOWN_GRACE);
+    let deadline = Instant::now() + grace_period;
+
+    loop {
+        match process.child.try_wait() {
+            Ok(Some(_)) => return Ok("Python backend stopped".to_string()),
+            Ok(None) => {}
+            Err(e) => return Err(format!("Failed to check backend status: {}", e)),
+        }
+
+        if Instant::now() >= deadline {
+            break;
+        }
+
+        std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
     }
+
+    process
+        .child
+        .kill()
+        .map_err(|e| format!("Failed to stop Python backend: {}", e))?;
+    let _ = process.child.wait();
+    Ok("Python backend stopped (forced)".to_string())
 }
 
 /// Check if Python backend is running
@@ -79,8 +447,8 @@ fn stop_python_backend(state: State<PythonBackend>) -> Result<String, String> {
 fn check_python_backend(state: State<PythonBackend>) -> bool {
     let mut backend = state.0.lock().unwrap();
 
-    if let Some(ref mut child) = *backend {
-        if let Ok(None) = child.try_wait() {
+    if let Some(ref mut process) = *backend {
+        if let Ok(None) = process.child.try_wait() {
             return true;
         }
     }
@@ -88,35 +456,269 @@ fn check_python_backend(state: State<PythonBackend>) -> bool {
     false
 }
 
+/// Persist a user-chosen Python interpreter path, tried before any
+/// auto-detection on the next backend start/restart.
+#[tauri::command]
+fn set_python_interpreter(
+    config_state: State<BackendConfigState>,
+    app_handle: AppHandle,
+    path: String,
+) -> Result<(), String> {
+    let mut config = config_state.0.lock().unwrap();
+    config.python_interpreter = Some(path);
+    config.save(&app_handle)
+}
+
+/// Persist the max number of consecutive restart attempts the supervisor
+/// will make before giving up and emitting `backend-failed`.
+#[tauri::command]
+fn set_max_restart_attempts(
+    config_state: State<BackendConfigState>,
+    supervisor: State<BackendSupervisor>,
+    app_handle: AppHandle,
+    max_restart_attempts: u32,
+) -> Result<(), String> {
+    let mut config = config_state.0.lock().unwrap();
+    config.max_restart_attempts = Some(max_restart_attempts);
+    config.save(&app_handle)?;
+
+    supervisor.0.lock().unwrap().max_restart_attempts = max_restart_attempts;
+
+    Ok(())
+}
+
+/// Manually restart the Python backend, bypassing the supervisor's backoff.
+#[tauri::command]
+fn restart_python_backend(
+    state: State<PythonBackend>,
+    supervisor: State<BackendSupervisor>,
+    app_handle: AppHandle,
+) -> Result<String, String> {
+    {
+        let mut backend = state.0.lock().unwrap();
+        if let Some(mut process) = backend.take() {
+            let _ = process.child.kill();
+            let _ = process.child.wait();
+        }
+    }
+
+    let (mut child, port) = spawn_backend_child(app_handle.clone())?;
+
+    if let Err(e) = wait_for_backend_ready(&app_handle, port) {
+        let _ = child.kill();
+        let _ = child.wait();
+        return Err(e);
+    }
+
+    let _ = app_handle.emit_all("backend-ready", BackendReady { port });
+
+    let mut backend = state.0.lock().unwrap();
+    *backend = Some(BackendProcess { child, port });
+
+    let mut sup = supervisor.0.lock().unwrap();
+    sup.restart_attempts = 0;
+    sup.started_at = Instant::now();
+
+    Ok("Python backend restarted successfully".to_string())
+}
+
+/// Watch the managed child process and recover from crashes with an
+/// exponential backoff, giving up after `max_restart_attempts` failures in a
+/// row.
+fn supervise_backend(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(SUPERVISOR_POLL_INTERVAL).await;
+
+            if app_handle.state::<ShutdownFlag>().0.load(Ordering::SeqCst) {
+                // The app is closing and is responsible for stopping the
+                // backend itself; stop watching it so we don't mistake that
+                // shutdown for a crash and respawn a new child.
+                break;
+            }
+
+            let exit_status = {
+                let state = app_handle.state::<PythonBackend>();
+                let mut backend = state.0.lock().unwrap();
+                match backend.as_mut() {
+                    Some(process) => process.child.try_wait().ok().flatten(),
+                    None => None,
+                }
+            };
+
+            let supervisor = app_handle.state::<BackendSupervisor>();
+
+            let Some(status) = exit_status else {
+                // Still running (or not started yet). Reset the restart
+                // counter once it has proven stable for a while.
+                let mut sup = supervisor.0.lock().unwrap();
+                if sup.restart_attempts > 0 && sup.started_at.elapsed() >= STABLE_UPTIME {
+                    sup.restart_attempts = 0;
+                }
+                continue;
+            };
+
+            if app_handle.state::<ShutdownFlag>().0.load(Ordering::SeqCst) {
+                // The exit we just observed is the app's own shutdown, not
+                // a crash. Don't respawn.
+                break;
+            }
+
+            {
+                let state = app_handle.state::<PythonBackend>();
+                *state.0.lock().unwrap() = None;
+            }
+
+            let _ = app_handle.emit_all(
+                "backend-crashed",
+                BackendCrashed {
+                    code: status.code(),
+                },
+            );
+
+            // Keep retrying right here, with backoff between attempts, until
+            // the backend comes back up or we exhaust max_restart_attempts.
+            // We can't just `continue` and let the next poll tick drive this:
+            // with no child in `PythonBackend`, the poll would see `None` and
+            // read that as "nothing to watch" rather than "crashed, retry".
+            loop {
+                let (attempts, max_attempts) = {
+                    let sup = supervisor.0.lock().unwrap();
+                    (sup.restart_attempts, sup.max_restart_attempts)
+                };
+
+                if attempts >= max_attempts {
+                    let _ = app_handle.emit_all("backend-failed", BackendFailed { attempts });
+                    break;
+                }
+
+                let delay = RESTART_BACKOFF_SECS
+                    .get(attempts as usize)
+                    .copied()
+                    .unwrap_or(*RESTART_BACKOFF_SECS.last().unwrap());
+                tokio::time::sleep(Duration::from_secs(delay)).await;
+
+                match spawn_backend_child(app_handle.clone()) {
+                    Ok((mut child, port)) => {
+                        if let Err(e) = wait_for_backend_ready(&app_handle, port) {
+                            eprintln!("Restarted Python backend failed to become ready: {}", e);
+                            let _ = child.kill();
+                            let _ = child.wait();
+                            let mut sup = supervisor.0.lock().unwrap();
+                            sup.restart_attempts += 1;
+                            continue;
+                        }
+
+                        let _ = app_handle.emit_all("backend-ready", BackendReady { port });
+
+                        let state = app_handle.state::<PythonBackend>();
+                        *state.0.lock().unwrap() = Some(BackendProcess { child, port });
+
+                        let mut sup = supervisor.0.lock().unwrap();
+                        sup.restart_attempts += 1;
+                        sup.started_at = Instant::now();
+                        break;
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to restart Python backend: {}", e);
+                        let mut sup = supervisor.0.lock().unwrap();
+                        sup.restart_attempts += 1;
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Show a native error dialog for a failed backend start/restart, with a
+/// "Retry" button that re-invokes `start_python_backend`. Dialogs must be
+/// shown off the main thread (they pump the GTK context on Linux), so this
+/// is spawned onto its own thread rather than blocking the caller.
+fn show_startup_error_dialog(app_handle: AppHandle, detail: String) {
+    std::thread::spawn(move || {
+        let retry = tauri::api::dialog::blocking::MessageDialogBuilder::new(
+            "Python Backend Failed to Start",
+            format!(
+                "syunjyu could not start its Python backend.\n\n{}",
+                detail
+            ),
+        )
+        .kind(tauri::api::dialog::MessageDialogKind::Error)
+        .buttons(tauri::api::dialog::MessageDialogButtons::OkCancelWithLabels(
+            "Retry".to_string(),
+            "Dismiss".to_string(),
+        ))
+        .show();
+
+        if retry {
+            let state = app_handle.state::<PythonBackend>();
+            if let Err(e) = start_python_backend(state, app_handle.clone()) {
+                show_startup_error_dialog(app_handle, e);
+            }
+        }
+    });
+}
+
 fn main() {
     tauri::Builder::default()
         .manage(PythonBackend(Mutex::new(None)))
+        .manage(BackendOutput(Mutex::new(VecDeque::with_capacity(
+            OUTPUT_BUFFER_LINES,
+        ))))
+        .manage(ShutdownFlag(AtomicBool::new(false)))
         .invoke_handler(tauri::generate_handler![
             start_python_backend,
             stop_python_backend,
             check_python_backend,
+            restart_python_backend,
+            set_python_interpreter,
+            set_max_restart_attempts,
         ])
         .setup(|app| {
-            // Auto-start Python backend on app startup
             let handle = app.handle();
-            tauri::async_runtime::spawn(async move {
-                // Wait a bit for the window to initialize
-                tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            let config = BackendConfig::load(&handle);
+            app.manage(BackendSupervisor(Mutex::new(SupervisorState::new(
+                config.max_restart_attempts.unwrap_or(DEFAULT_MAX_RESTART_ATTEMPTS),
+            ))));
+            app.manage(BackendConfigState(Mutex::new(config)));
 
-                // Start the backend
+            // Auto-start Python backend on app startup. start_python_backend
+            // blocks until the backend's health check passes (or times out),
+            // so there's no need to guess how long startup takes here.
+            let handle = app.handle();
+            tauri::async_runtime::spawn(async move {
                 let state = handle.state::<PythonBackend>();
-                if let Err(e) = start_python_backend(state) {
-                    eprintln!("Failed to auto-start Python backend: {}", e);
+                if let Err(e) = start_python_backend(state, handle.clone()) {
+                    show_startup_error_dialog(handle.clone(), e);
                 }
+
+                supervise_backend(handle.clone());
             });
 
             Ok(())
         })
         .on_window_event(|event| {
-            if let tauri::WindowEvent::CloseRequested { .. } = event.event() {
-                // Stop Python backend when app is closing
-                let state = event.window().state::<PythonBackend>();
-                let _ = stop_python_backend(state);
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event.event() {
+                let window = event.window().clone();
+                // The window.close() below raises CloseRequested again; once
+                // the shutdown flag is set, let that close go through.
+                if window.state::<ShutdownFlag>().0.swap(true, Ordering::SeqCst) {
+                    return;
+                }
+                // Stop the Python backend when app is closing, reaping the
+                // child so it isn't left running as an orphan. The graceful
+                // shutdown sequence can block for several seconds (the
+                // /shutdown POST timeout plus the full grace period), so we
+                // prevent the close, run it on a background thread, and
+                // close the window ourselves once it's done rather than
+                // blocking the window-event thread.
+                api.prevent_close();
+                std::thread::spawn(move || {
+                    let state = window.state::<PythonBackend>();
+                    let config_state = window.state::<BackendConfigState>();
+                    let _ = stop_python_backend(state, config_state);
+                    let _ = window.close();
+                });
             }
         })
         .run(tauri::generate_context!())